@@ -1,4 +1,6 @@
 use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
     error::Error,
     fs::File,
     io::{stdout, Write},
@@ -7,7 +9,7 @@ use std::{
 };
 
 use clap::Parser;
-use rand::prelude::*;
+use rand::{prelude::*, rngs::StdRng};
 use rayon::prelude::*;
 
 trait Strategy {
@@ -20,43 +22,163 @@ trait Strategy {
     }
 }
 
-fn simulate_strategy(args: &Args, strategy: &impl Strategy) -> usize {
-    let mut rng = thread_rng();
+/// Result of simulating a strategy, including the running Welford estimate of the
+/// 95% confidence half-width on `rate` so callers can judge whether `n` was enough
+/// samples to trust the comparison.
+struct SimulationResult {
+    n: usize,
+    rate: f64,
+    half_width: f64,
+}
+
+fn simulate_strategy(args: &OptimizeArgs, strategy: &impl Strategy, strategy_index: usize) -> SimulationResult {
+    let mut rng: StdRng = match args.seed {
+        Some(seed) => StdRng::seed_from_u64(seed ^ strategy_index as u64),
+        None => StdRng::from_entropy(),
+    };
 
     let mut counter = 0;
     let mut drops = 0;
 
+    // Welford's online algorithm for the mean and variance of the per-step drop indicator
+    let mut n = 0usize;
+    let mut mean = 0.0;
+    let mut m2 = 0.0;
+    let mut half_width = f64::INFINITY;
+
     for _ in 0..args.sim_steps_per_strategy {
         let counter_active = strategy.decide(counter);
 
         // kill enemy
         counter += 1;
 
-        if counter >= args.max_counter_value {
+        let step_drop = if counter >= args.max_counter_value {
             // guaranteed pity drop given
             counter = 0;
             drops += 1;
-            continue;
-        }
-
-        // check for random drop from killed enemy
-        let drop_chance = if counter_active {
-            args.base_drop_rate + args.counter_multiplier * (counter as f64)
+            1.0
         } else {
-            args.base_drop_rate
+            // check for random drop from killed enemy
+            let drop_chance = if counter_active {
+                args.base_drop_rate + args.counter_multiplier * (counter as f64)
+            } else {
+                args.base_drop_rate
+            };
+
+            let drop_roll: f64 = rng.gen();
+            if drop_roll < drop_chance {
+                // random item drop acquired
+                drops += 1;
+                if counter_active {
+                    counter = 0;
+                }
+                1.0
+            } else {
+                0.0
+            }
         };
 
-        let drop_roll: f64 = rng.gen();
-        if drop_roll < drop_chance {
-            // random item drop acquired
-            drops += 1;
-            if counter_active {
-                counter = 0;
+        n += 1;
+        let delta = step_drop - mean;
+        mean += delta / (n as f64);
+        let delta2 = step_drop - mean;
+        m2 += delta * delta2;
+
+        if n > 1 {
+            let variance = m2 / ((n - 1) as f64);
+            half_width = 1.96 * (variance / (n as f64)).sqrt();
+
+            if let Some(target_error) = args.target_error {
+                if half_width < target_error {
+                    break;
+                }
             }
         }
     }
 
-    drops
+    SimulationResult {
+        n,
+        rate: (drops as f64) / (n as f64),
+        half_width,
+    }
+}
+
+/// Exactly evaluates the long-run drops-per-kill of a strategy by treating the
+/// counter as a finite Markov chain and solving for its stationary distribution,
+/// rather than estimating it by sampling.
+fn evaluate_strategy_exact(
+    base_drop_rate: f64,
+    counter_multiplier: f64,
+    max_counter_value: usize,
+    strategy: &(impl Strategy + ?Sized),
+) -> f64 {
+    let m = max_counter_value;
+
+    // reward[c] is the expected per-kill drop reward when starting a kill at counter c.
+    // Note in both the active and inactive case this collapses to just `p`: an active
+    // hit gives reward 1 with probability p and 0 otherwise, while an inactive kill
+    // always advances but still rolls the random drop with probability p.
+    let reward: Vec<f64> = (0..m)
+        .map(|c| {
+            if c == m - 1 {
+                1.0
+            } else {
+                let active = strategy.decide(c);
+                let n = c + 1;
+                (base_drop_rate
+                    + if active {
+                        counter_multiplier * (n as f64)
+                    } else {
+                        0.0
+                    })
+                .min(1.0)
+            }
+        })
+        .collect();
+
+    // power iteration over the stationary distribution; state 0 is always reachable
+    // via the pity reset (and via active-strategy hits), so the chain is ergodic and
+    // this converges from a uniform start. `10_000` is only an upper-bound safety cap:
+    // we break as soon as the per-state change drops below `EPSILON`, which is what
+    // actually makes the result converged rather than "ran a fixed number of times".
+    const MAX_ITERS: usize = 10_000;
+    const EPSILON: f64 = 1e-10;
+    let mut pi = vec![1.0 / (m as f64); m];
+    let mut converged = false;
+    for _ in 0..MAX_ITERS {
+        let mut next = vec![0.0; m];
+        for c in 0..m {
+            if c == m - 1 {
+                next[0] += pi[c];
+                continue;
+            }
+            let active = strategy.decide(c);
+            let n = c + 1;
+            if active {
+                let p = reward[c];
+                next[0] += pi[c] * p;
+                next[n] += pi[c] * (1.0 - p);
+            } else {
+                next[n] += pi[c];
+            }
+        }
+        let max_delta = pi
+            .iter()
+            .zip(next.iter())
+            .fold(0.0f64, |acc, (&old, &new)| acc.max((new - old).abs()));
+        pi = next;
+        if max_delta < EPSILON {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        eprintln!(
+            "warning: evaluate_strategy_exact hit the {MAX_ITERS}-iteration cap without converging to {EPSILON}"
+        );
+    }
+
+    pi.iter().zip(reward.iter()).map(|(p, r)| p * r).sum()
 }
 
 struct NaiveThreshold(usize);
@@ -89,8 +211,15 @@ impl Strategy for XorThresholds {
     }
 }
 
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum Optimizer {
+    Greedy,
+    Anneal,
+    Beam,
+}
+
 #[derive(Parser)]
-struct Args {
+struct OptimizeArgs {
     #[clap(short, long, default_value_t = 0.001)]
     base_drop_rate: f64,
 
@@ -105,10 +234,294 @@ struct Args {
 
     #[clap(short, long)]
     out: Option<PathBuf>,
+
+    /// Replace Monte Carlo simulation with the exact Markov-chain evaluator
+    #[clap(long)]
+    exact: bool,
+
+    /// Search strategy used to place thresholds
+    #[clap(long, value_enum, default_value_t = Optimizer::Greedy)]
+    optimizer: Optimizer,
+
+    #[clap(long, default_value_t = 1.0)]
+    anneal_start_temp: f64,
+
+    #[clap(long, default_value_t = 0.995)]
+    anneal_alpha: f64,
+
+    #[clap(long, default_value_t = 10_000)]
+    anneal_iters: usize,
+
+    /// Number of candidate threshold configurations kept alive per round by the beam optimizer
+    #[clap(long, default_value_t = 8)]
+    beam_width: usize,
+
+    /// Seed for a deterministic per-strategy RNG (simulated strategy index is XORed in)
+    #[clap(long)]
+    seed: Option<u64>,
+
+    /// Stop simulating a strategy early once its 95% confidence half-width drops below this
+    #[clap(long)]
+    target_error: Option<f64>,
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let args = Args::parse();
+/// A strategy's evaluated drop rate, with sampling confidence info when it came from
+/// `simulate_strategy` rather than the exact evaluator (`n == 0` for exact results).
+struct CandidateEval {
+    rate: f64,
+    n: usize,
+    half_width: f64,
+}
+
+fn evaluate_candidate(args: &OptimizeArgs, strategy: &impl Strategy, index: usize) -> CandidateEval {
+    if args.exact {
+        CandidateEval {
+            rate: evaluate_strategy_exact(
+                args.base_drop_rate,
+                args.counter_multiplier,
+                args.max_counter_value,
+                strategy,
+            ),
+            n: 0,
+            half_width: 0.0,
+        }
+    } else {
+        let sim = simulate_strategy(args, strategy, index);
+        CandidateEval {
+            rate: sim.rate,
+            n: sim.n,
+            half_width: sim.half_width,
+        }
+    }
+}
+
+fn strategy_rate(args: &OptimizeArgs, strategy: &impl Strategy, index: usize) -> f64 {
+    evaluate_candidate(args, strategy, index).rate
+}
+
+/// Renders a trailing `" (n=..., 95% CI ±...)"` clause for sampled evaluations, or an
+/// empty string for exact (non-sampled) ones.
+fn format_ci(eval: &CandidateEval) -> String {
+    if eval.n == 0 {
+        String::new()
+    } else {
+        format!(" (n={}, 95% CI ±{})", eval.n, eval.half_width)
+    }
+}
+
+/// Neighbor move applied to a threshold configuration during simulated annealing.
+enum Move {
+    Insert(usize),
+    Delete(usize),
+    Shift(usize, i64),
+}
+
+fn random_neighbor(rng: &mut impl Rng, thresholds: &[usize], max_counter_value: usize) -> Move {
+    let choices = if thresholds.is_empty() {
+        // nothing to delete or shift yet
+        0
+    } else {
+        rng.gen_range(0..3)
+    };
+
+    match choices {
+        0 => loop {
+            let candidate = rng.gen_range(0..=max_counter_value);
+            if !thresholds.contains(&candidate) {
+                break Move::Insert(candidate);
+            }
+        },
+        1 => Move::Delete(rng.gen_range(0..thresholds.len())),
+        _ => {
+            let index = rng.gen_range(0..thresholds.len());
+            let delta = rng.gen_range(-10..=10);
+            Move::Shift(index, delta)
+        }
+    }
+}
+
+fn apply_move(thresholds: &[usize], mv: Move, max_counter_value: usize) -> Vec<usize> {
+    let mut next = thresholds.to_vec();
+    match mv {
+        Move::Insert(t) => next.push(t),
+        Move::Delete(i) => {
+            next.remove(i);
+        }
+        Move::Shift(i, delta) => {
+            let shifted = (next[i] as i64 + delta).clamp(0, max_counter_value as i64);
+            next[i] = shifted as usize;
+        }
+    }
+    next
+}
+
+fn run_anneal(args: &OptimizeArgs) -> (Vec<usize>, f64) {
+    // Seed the search trajectory itself (not just the per-candidate simulator) so that
+    // `--seed` makes a full `--optimizer anneal` run reproducible end-to-end.
+    let mut rng = StdRng::seed_from_u64(args.seed.unwrap_or_else(rand::random));
+
+    let mut thresholds: Vec<usize> = vec![];
+    let mut rate = strategy_rate(args, &XorInverseThresholds(thresholds.clone()), 0);
+
+    let mut best_thresholds = thresholds.clone();
+    let mut best_rate = rate;
+
+    let mut temp = args.anneal_start_temp;
+
+    for i in 0..args.anneal_iters {
+        let candidate =
+            apply_move(&thresholds, random_neighbor(&mut rng, &thresholds, args.max_counter_value), args.max_counter_value);
+        let candidate_rate = strategy_rate(args, &XorInverseThresholds(candidate.clone()), i + 1);
+
+        let accept = candidate_rate > rate
+            || rng.gen::<f64>() < ((candidate_rate - rate) / temp).exp();
+
+        if accept {
+            thresholds = candidate;
+            rate = candidate_rate;
+
+            if rate > best_rate {
+                best_rate = rate;
+                best_thresholds = thresholds.clone();
+            }
+        }
+
+        temp *= args.anneal_alpha;
+
+        print!("\rannealing: iteration {}/{}, T={temp:.5}, best rate={best_rate}", i + 1, args.anneal_iters);
+        stdout().flush().unwrap();
+    }
+    println!();
+
+    (best_thresholds, best_rate)
+}
+
+/// A threshold configuration together with its evaluated drop rate, ordered by rate so it
+/// can be kept in a bounded min-heap (via `Reverse`) that retains the best candidates
+/// during beam search.
+#[derive(Clone)]
+struct ScoredThresholds {
+    rate: f64,
+    thresholds: Vec<usize>,
+}
+
+impl PartialEq for ScoredThresholds {
+    fn eq(&self, other: &Self) -> bool {
+        self.rate == other.rate
+    }
+}
+impl Eq for ScoredThresholds {}
+impl PartialOrd for ScoredThresholds {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for ScoredThresholds {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rate
+            .partial_cmp(&other.rate)
+            .expect("drop rates are never NaN")
+    }
+}
+
+fn run_beam(args: &OptimizeArgs) -> (Vec<usize>, f64) {
+    let initial_rate = strategy_rate(args, &XorInverseThresholds(vec![]), 0);
+    let mut beam = vec![ScoredThresholds {
+        rate: initial_rate,
+        thresholds: vec![],
+    }];
+    let mut best_rate = initial_rate;
+    let mut round = 0;
+
+    loop {
+        round += 1;
+
+        let successors: Vec<Vec<usize>> = beam
+            .iter()
+            .flat_map(|candidate| {
+                (0..=args.max_counter_value)
+                    .filter(|t| !candidate.thresholds.contains(t))
+                    .map(|t| {
+                        let mut next = candidate.thresholds.clone();
+                        next.push(t);
+                        next
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let scored: Vec<ScoredThresholds> = successors
+            .into_par_iter()
+            .enumerate()
+            .map(|(index, thresholds)| {
+                let rate = strategy_rate(args, &XorInverseThresholds(thresholds.clone()), index);
+                ScoredThresholds { rate, thresholds }
+            })
+            .collect();
+
+        // keep only the beam_width highest-scoring distinct threshold sets
+        let mut seen = HashSet::new();
+        let mut heap: BinaryHeap<Reverse<ScoredThresholds>> = BinaryHeap::new();
+        for candidate in scored {
+            let mut key = candidate.thresholds.clone();
+            key.sort_unstable();
+            if !seen.insert(key) {
+                continue;
+            }
+
+            if heap.len() < args.beam_width {
+                heap.push(Reverse(candidate));
+            } else if heap.peek().is_some_and(|Reverse(min)| candidate.rate > min.rate) {
+                heap.pop();
+                heap.push(Reverse(candidate));
+            }
+        }
+
+        let mut next_beam: Vec<ScoredThresholds> = heap.into_iter().map(|Reverse(c)| c).collect();
+        next_beam.sort_by(|a, b| b.cmp(a));
+
+        let round_best = next_beam
+            .first()
+            .map(|c| c.rate)
+            .unwrap_or(f64::NEG_INFINITY);
+
+        println!("beam round {round}: {} candidates retained, best rate this round {round_best}", next_beam.len());
+
+        if round_best <= best_rate {
+            println!("No further optimization can be made");
+            break;
+        }
+
+        best_rate = round_best;
+        beam = next_beam;
+    }
+
+    let best = beam
+        .into_iter()
+        .max()
+        .expect("beam is never empty");
+
+    (best.thresholds, best.rate)
+}
+
+fn run_optimize(args: &OptimizeArgs) -> Result<(), Box<dyn Error>> {
+    if let Optimizer::Anneal | Optimizer::Beam = args.optimizer {
+        let (thresholds, rate) = match args.optimizer {
+            Optimizer::Anneal => run_anneal(args),
+            Optimizer::Beam => run_beam(args),
+            Optimizer::Greedy => unreachable!(),
+        };
+        println!("Best thresholds found: {thresholds:?}, yielding a rate of {rate} drops per kill");
+
+        if let Some(out) = &args.out {
+            let filepath_string = out.to_string_lossy().to_string();
+            let mut out_file = File::create(out)?;
+            writeln!(out_file, "{thresholds:?},{rate}")?;
+            println!("Saved results to {filepath_string}");
+        }
+
+        return Ok(());
+    }
 
     let mut thresholds = vec![];
 
@@ -120,12 +533,12 @@ fn main() -> Result<(), Box<dyn Error>> {
         let strategy_finish_counter = AtomicUsize::new(0);
         let num_strategies = args.max_counter_value + 1;
 
-        let results: Vec<_> = (0..num_strategies)
+        let results: Vec<CandidateEval> = (0..num_strategies)
             .into_par_iter()
             .map(|strategy_index| {
                 let mut my_thresholds = thresholds.clone();
                 my_thresholds.push(strategy_index);
-                let drops = simulate_strategy(&args, &XorInverseThresholds(my_thresholds));
+                let eval = evaluate_candidate(args, &XorInverseThresholds(my_thresholds), strategy_index);
                 let strategy_finish_counter_value = strategy_finish_counter.fetch_add(1, SeqCst);
                 print!(
                     "\rfinished {}/{}",
@@ -134,15 +547,15 @@ fn main() -> Result<(), Box<dyn Error>> {
                 );
                 stdout().flush().unwrap();
 
-                drops
+                eval
             })
             .collect();
         println!();
 
-        let (max_strategy, &max_drops) = results
+        let (max_strategy, max_eval) = results
             .iter()
             .enumerate()
-            .max_by_key(|(_, &d)| d)
+            .max_by(|(_, a), (_, b)| a.rate.partial_cmp(&b.rate).expect("drop rates are never NaN"))
             .expect("Results vector will be non-empty");
 
         if max_strategy == num_strategies - 1 {
@@ -150,7 +563,11 @@ fn main() -> Result<(), Box<dyn Error>> {
             break results;
         }
 
-        println!("Determined optimal strategy index to be #{max_strategy}, yielding {max_drops} drops at a rate of {} drops per kill", (max_drops as f64) / (args.sim_steps_per_strategy as f64));
+        println!(
+            "Determined optimal strategy index to be #{max_strategy}, yielding a rate of {} drops per kill{}",
+            max_eval.rate,
+            format_ci(max_eval)
+        );
 
         if thresholds.contains(&max_strategy) {
             thresholds.retain(|&x| x != max_strategy);
@@ -162,24 +579,17 @@ fn main() -> Result<(), Box<dyn Error>> {
     };
 
     println!("Final simulation results:");
-    for (i, &drops) in results.iter().enumerate() {
-        println!(
-            "strategy {i}: {drops} drops, {} drops per kill",
-            (drops as f64) / (args.sim_steps_per_strategy as f64)
-        );
+    for (i, eval) in results.iter().enumerate() {
+        println!("strategy {i}: {} drops per kill{}", eval.rate, format_ci(eval));
     }
 
     println!("Final strategy thresholds: {thresholds:?}");
 
-    if let Some(out) = args.out {
+    if let Some(out) = &args.out {
         let filepath_string = out.to_string_lossy().to_string();
         let mut out_file = File::create(out)?;
-        for (i, drops) in results.into_iter().enumerate() {
-            writeln!(
-                out_file,
-                "{i},{drops},{}",
-                (drops as f64) / (args.sim_steps_per_strategy as f64)
-            )?;
+        for (i, eval) in results.into_iter().enumerate() {
+            writeln!(out_file, "{i},{},{},{}", eval.rate, eval.n, eval.half_width)?;
         }
         println!("Saved results to {filepath_string}");
     }
@@ -187,6 +597,196 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[derive(Parser)]
+struct PlotArgs {
+    /// CSV file of `index,rate,...` rows to plot, as written by `optimize --out` with the
+    /// greedy optimizer
+    #[clap(long = "in")]
+    input: PathBuf,
+
+    #[clap(long)]
+    out: PathBuf,
+}
+
+/// A row of the CSV written by `optimize --out` with the greedy optimizer:
+/// `index,rate,n,half_width`. The anneal and beam optimizers write a single
+/// `[thresholds],rate` line instead, which this type does not parse. Kept as a
+/// named type (rather than inlined in `cmd_plot`) so a test can assert it stays in
+/// sync with the columns `run_optimize` actually writes.
+#[derive(serde::Deserialize)]
+#[allow(dead_code)]
+struct ResultRecord(usize, f64, usize, f64);
+
+fn read_results_csv(path: &std::path::Path) -> Result<Vec<ResultRecord>, Box<dyn Error>> {
+    use csv::ReaderBuilder;
+
+    ReaderBuilder::new()
+        .has_headers(false)
+        .from_path(path)?
+        .deserialize()
+        .collect::<Result<_, _>>()
+        .map_err(Into::into)
+}
+
+fn cmd_plot(args: &PlotArgs) -> Result<(), Box<dyn Error>> {
+    use plotters::prelude::*;
+    use plotters::style::full_palette::GREEN_500;
+
+    let points = read_results_csv(&args.input)?;
+
+    let max_index = points.iter().map(|ResultRecord(index, ..)| *index).max().unwrap_or(0);
+    let (min_rate, max_rate) = points.iter().fold(
+        (f64::INFINITY, f64::NEG_INFINITY),
+        |(lo, hi), ResultRecord(_, rate, ..)| (lo.min(*rate), hi.max(*rate)),
+    );
+    // auto-scale the y-axis with a small margin so points don't sit flush on the chart edge
+    let margin = ((max_rate - min_rate) * 0.05).max(f64::EPSILON);
+
+    let root = BitMapBackend::new(&args.out, (1280, 720)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut plot = ChartBuilder::on(&root)
+        .margin(20)
+        .x_label_area_size(30)
+        .y_label_area_size(60)
+        .build_cartesian_2d(0usize..max_index, (min_rate - margin)..(max_rate + margin))?;
+    plot.configure_mesh().draw()?;
+    plot.draw_series(
+        points
+            .iter()
+            .map(|ResultRecord(index, rate, ..)| Circle::new((*index, *rate), 2, GREEN_500.filled())),
+    )?;
+    root.present()?;
+    println!("saved {}", args.out.to_string_lossy());
+
+    Ok(())
+}
+
+#[derive(Parser)]
+struct CompareArgs {
+    /// Strategy constructors to overlay, e.g. `naive-threshold:500` or `xor-inverse:541,950`
+    #[clap(required = true)]
+    strategies: Vec<String>,
+
+    #[clap(short, long, default_value_t = 0.001)]
+    base_drop_rate: f64,
+
+    #[clap(short, long, default_value_t = 0.000002)]
+    counter_multiplier: f64,
+
+    #[clap(short, long, default_value_t = 1000)]
+    max_counter_value: usize,
+
+    #[clap(long)]
+    out: PathBuf,
+}
+
+/// Parses a `name:t1,t2,...` spec into the matching `Strategy`. Recognized names are
+/// `naive-threshold`, `naive-inverse-threshold`, `xor-thresholds` and `xor-inverse`.
+fn parse_strategy_spec(spec: &str) -> Result<Box<dyn Strategy>, String> {
+    let (kind, params) = spec
+        .split_once(':')
+        .ok_or_else(|| format!("invalid strategy spec `{spec}`, expected `name:thresholds`"))?;
+
+    let thresholds: Vec<usize> = params
+        .split(',')
+        .map(|p| {
+            p.parse::<usize>()
+                .map_err(|_| format!("invalid threshold `{p}` in `{spec}`"))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let first = || match thresholds.as_slice() {
+        [t] => Ok(*t),
+        _ => Err(format!("`{kind}` requires exactly one threshold in `{spec}`")),
+    };
+
+    let strategy: Box<dyn Strategy> = match kind {
+        "naive-threshold" => Box::new(NaiveThreshold(first()?)),
+        "naive-inverse-threshold" => Box::new(NaiveInverseThreshold(first()?)),
+        "xor-thresholds" => Box::new(XorThresholds(thresholds)),
+        "xor-inverse" => Box::new(XorInverseThresholds(thresholds)),
+        other => return Err(format!("unknown strategy kind `{other}` in `{spec}`")),
+    };
+
+    Ok(strategy)
+}
+
+fn cmd_compare(args: &CompareArgs) -> Result<(), Box<dyn Error>> {
+    use plotters::prelude::*;
+    use plotters::style::full_palette::{BLUE_500, GREEN_500, ORANGE_500, PURPLE_500, RED_500, TEAL_500};
+
+    let palette = [RED_500, BLUE_500, GREEN_500, ORANGE_500, PURPLE_500, TEAL_500];
+
+    let strategies = args
+        .strategies
+        .iter()
+        .map(|spec| {
+            let strategy = parse_strategy_spec(spec)
+                .map_err(|e| -> Box<dyn Error> { e.into() })?;
+            let rate = evaluate_strategy_exact(
+                args.base_drop_rate,
+                args.counter_multiplier,
+                args.max_counter_value,
+                strategy.as_ref(),
+            );
+            Ok::<_, Box<dyn Error>>((spec.clone(), strategy, rate))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let root = BitMapBackend::new(&args.out, (1280, 720)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut plot = ChartBuilder::on(&root)
+        .margin(20)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .caption("Strategy activation curves", ("sans-serif", 24))
+        .build_cartesian_2d(0usize..args.max_counter_value, -0.1f64..1.1)?;
+    plot.configure_mesh().y_desc("decide(counter)").draw()?;
+
+    for ((spec, strategy, rate), &color) in strategies.iter().zip(palette.iter().cycle()) {
+        plot.draw_series(LineSeries::new(
+            (0..=args.max_counter_value).map(|c| (c, if strategy.decide(c) { 1.0 } else { 0.0 })),
+            color,
+        ))?
+        .label(format!("{spec} ({rate} drops/kill)"))
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+
+    plot.configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+    root.present()?;
+    println!("saved {}", args.out.to_string_lossy());
+
+    Ok(())
+}
+
+#[derive(Parser)]
+#[command(name = "drops")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Search for an optimal threshold placement
+    Optimize(OptimizeArgs),
+    /// Render a results CSV (as written by greedy `optimize --out`) to an image
+    Plot(PlotArgs),
+    /// Overlay several strategies' activation curves and drop rates on one chart
+    Compare(CompareArgs),
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    match Cli::parse().command {
+        Command::Optimize(args) => run_optimize(&args),
+        Command::Plot(args) => cmd_plot(&args),
+        Command::Compare(args) => cmd_compare(&args),
+    }
+}
+
 #[test]
 fn test1() {
     let strategy = XorInverseThresholds(vec![541, 950]);
@@ -194,32 +794,51 @@ fn test1() {
 }
 
 #[test]
-fn plot() -> Result<(), Box<dyn Error>> {
-    use csv::Reader;
-    use plotters::prelude::*;
-    use plotters::style::full_palette::*;
-    use serde::Deserialize;
-
-    #[derive(Deserialize)]
-    struct Record(usize, usize, f64);
-
-    for data_source in ["data", "early-data", "xor-data", "xor-data-3"] {
-        let in_file = format!("{data_source}.csv");
-        let out_img = format!("{data_source}.png");
-        let root = BitMapBackend::new(&out_img, (1280, 720)).into_drawing_area();
-        let points: Vec<Record> = Reader::from_path(in_file)?
-            .deserialize()
-            .collect::<Result<_, _>>()?;
-        let mut plot =
-            ChartBuilder::on(&root).build_cartesian_2d(0usize..1000, 0.00185f64..0.0021)?;
-        plot.draw_series(
-            points
-                .iter()
-                .map(|Record(index, _, rate)| Circle::new((*index, *rate), 2, GREEN_500.filled())),
-        )?;
-        root.present()?;
-        println!("saved {out_img}");
-    }
+fn evaluate_strategy_exact_matches_hand_computed_rate() {
+    // An always-inactive strategy over a small M turns the chain into a deterministic
+    // 0..M-1 cycle visited uniformly, so the exact rate has a closed form:
+    // (1/M) * ((M-1) * base_drop_rate + 1), with the `+1` from the guaranteed pity drop.
+    let strategy = NaiveThreshold(1_000);
+    let m = 5;
+    let base_drop_rate = 0.1;
+    let expected = ((m - 1) as f64 * base_drop_rate + 1.0) / (m as f64);
+
+    let rate = evaluate_strategy_exact(base_drop_rate, 0.0, m, &strategy);
+
+    assert!(
+        (rate - expected).abs() < 1e-9,
+        "expected {expected}, got {rate}"
+    );
+}
 
-    Ok(())
+#[test]
+fn parse_strategy_spec_errors() {
+    assert!(parse_strategy_spec("no-colon-here").is_err());
+    assert!(parse_strategy_spec("naive-threshold:abc").is_err());
+    assert!(parse_strategy_spec("unknown-kind:1").is_err());
+    assert!(parse_strategy_spec("naive-threshold:").is_err());
+
+    assert!(parse_strategy_spec("naive-threshold:500").is_ok());
+    assert!(parse_strategy_spec("xor-inverse:541,950").is_ok());
+}
+
+#[test]
+fn results_csv_round_trip_matches_optimize_writer() {
+    // Mirrors the `writeln!(out_file, "{i},{},{},{}", ...)` format in `run_optimize`
+    // so a column reorder there is caught here instead of silently breaking `cmd_plot`.
+    let path = std::env::temp_dir().join("drops_results_csv_round_trip_test.csv");
+    let mut file = File::create(&path).unwrap();
+    writeln!(file, "0,0.0021,1000,0.0003").unwrap();
+    writeln!(file, "1,0.0034,2000,0.0002").unwrap();
+    drop(file);
+
+    let records = read_results_csv(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].0, 0);
+    assert!((records[0].1 - 0.0021).abs() < 1e-12);
+    assert_eq!(records[0].2, 1000);
+    assert!((records[0].3 - 0.0003).abs() < 1e-12);
+    assert_eq!(records[1].2, 2000);
 }